@@ -0,0 +1,103 @@
+use core::convert::Infallible;
+use core::ops::ControlFlow;
+
+/// Generalizes [`try_from_fn`](super::try_from_fn) over any short-circuiting type, not just
+/// [`Option`] and [`Result`]. Modeled on the unstable `core::ops::Try` trait rustc uses for
+/// [`array::try_from_fn`](core::array::try_from_fn).
+pub trait Branch {
+    /// The "keep going" value.
+    type Output;
+    /// The value carried by a short circuit.
+    type Residual;
+    /// Wraps an [`Output`](Branch::Output) back up into `Self`.
+    fn from_output(output: Self::Output) -> Self;
+    /// Deconstructs `self` into its continue/break halves.
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+/// Rebuilds a `Self` from a [`Branch::Residual`], letting the [`Output`](Branch::Output)
+/// differ from the one that originally produced the residual.
+pub trait FromResidual<R = <Self as Branch>::Residual>: Branch<Residual = R> {
+    /// Reconstructs `Self` from a short-circuited residual.
+    fn from_residual(residual: R) -> Self;
+}
+
+/// Links a [`Branch::Residual`] to the family of types it can be rebuilt into for a given
+/// `Output`. Lets [`try_from_fn`](super::try_from_fn) swap the array's element type while
+/// keeping the caller's short-circuit wrapper (`Option`, `Result`, `ControlFlow`, ...).
+pub trait Residual<O>: Sized {
+    /// The wrapper this residual reconstructs into, with [`Branch::Output`] set to `O`.
+    type TryType: FromResidual<Self, Output = O>;
+}
+
+impl<T> Branch for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+    fn from_output(output: T) -> Self {
+        Some(output)
+    }
+    fn branch(self) -> ControlFlow<Self::Residual, T> {
+        match self {
+            Some(v) => ControlFlow::Continue(v),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+impl<T> FromResidual for Option<T> {
+    fn from_residual(_: Option<Infallible>) -> Self {
+        None
+    }
+}
+impl<O> Residual<O> for Option<Infallible> {
+    type TryType = Option<O>;
+}
+
+impl<T, E> Branch for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+    fn branch(self) -> ControlFlow<Self::Residual, T> {
+        match self {
+            Ok(v) => ControlFlow::Continue(v),
+            Err(e) => ControlFlow::Break(Err(e)),
+        }
+    }
+}
+impl<T, E> FromResidual for Result<T, E> {
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Err(e) => Err(e),
+            Ok(never) => match never {},
+        }
+    }
+}
+impl<O, E> Residual<O> for Result<Infallible, E> {
+    type TryType = Result<O, E>;
+}
+
+impl<B, C> Branch for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, Infallible>;
+    fn from_output(output: C) -> Self {
+        ControlFlow::Continue(output)
+    }
+    fn branch(self) -> ControlFlow<Self::Residual, C> {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(c),
+            ControlFlow::Break(b) => ControlFlow::Break(ControlFlow::Break(b)),
+        }
+    }
+}
+impl<B, C> FromResidual for ControlFlow<B, C> {
+    fn from_residual(residual: ControlFlow<B, Infallible>) -> Self {
+        match residual {
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+            ControlFlow::Continue(never) => match never {},
+        }
+    }
+}
+impl<B, O> Residual<O> for ControlFlow<B, Infallible> {
+    type TryType = ControlFlow<B, O>;
+}
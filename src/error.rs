@@ -1,35 +1,41 @@
 /// This error is returned by [`try_collect_array`](super::CollectArray::try_collect_array)
+/// and [`collect_array_exact`](super::CollectArray::collect_array_exact).
 #[derive(Clone, Copy, Hash)]
 pub struct Error<const N: usize, E> {
     /// Error returned by <code>[next](Iterator::next)()?.error</code> (`()` if [`None`]).
     pub error: Option<E>,
-    /// Point of error.
+    /// Point of error: elements filled before running dry, or `N` when [`too_many`](Error::too_many) is set.
     pub at: usize,
+    /// Set when the iterator kept yielding past `N` instead of running dry.
+    pub too_many: bool,
 }
 
 impl<const N: usize, const O: usize, E: PartialEq> PartialEq<Error<O, E>> for Error<N, E> {
     fn eq(&self, other: &Error<O, E>) -> bool {
-        (self.error == other.error) & (self.at == other.at)
+        (self.error == other.error) & (self.at == other.at) & (self.too_many == other.too_many)
     }
 }
 
 impl<const N: usize, E: core::fmt::Display> core::fmt::Display for Error<N, E> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match (&self.error, &self.at) {
-            (Some(x), at) => write!(f, "{x} @ {at} of {N}"),
-            (None, at) => write!(
+        match (&self.error, self.too_many) {
+            (Some(x), _) => write!(f, "{x} @ {} of {N}", self.at),
+            (None, false) => write!(
                 f,
-                "couldnt fill array of length {N}, only had {at} elements.",
+                "couldnt fill array of length {N}, only had {} elements.",
+                self.at
             ),
+            (None, true) => write!(f, "expected exactly {N} elements, got more."),
         }
     }
 }
 
 impl<const N: usize, E: core::fmt::Debug> core::fmt::Debug for Error<N, E> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match (&self.error, &self.at) {
-            (Some(x), at) => write!(f, "{x:?} @ {at} of {N}"),
-            (None, at) => write!(f, "Size(wanted {N}, had {at})"),
+        match (&self.error, self.too_many) {
+            (Some(x), _) => write!(f, "{x:?} @ {} of {N}", self.at),
+            (None, false) => write!(f, "Size(wanted {N}, had {})", self.at),
+            (None, true) => write!(f, "Size(wanted {N}, had more)"),
         }
     }
 }
@@ -13,12 +13,15 @@
 
 use core::{
     mem::{ManuallyDrop as MD, MaybeUninit as MU, forget},
+    ops::ControlFlow,
     ptr::drop_in_place,
 };
 use error::Error;
 pub use error::Error as CollectorError;
+mod branch;
 mod error;
 mod maybe;
+pub use branch::{Branch, FromResidual, Residual};
 use maybe::Maybe;
 
 /// Collect to an array.
@@ -89,10 +92,50 @@ pub trait CollectArray: Iterator + Sized {
                 .ok_or(None)
                 // some error, flattened
                 .and_then(|x| x.asr().map_err(Some))
-                .map_err(|x| Error { error: x, at: elem })
+                .map_err(|x| Error {
+                    error: x,
+                    at: elem,
+                    too_many: false,
+                })
         })
     }
 
+    /// Checks that the iterator yields *exactly* `N` items.
+    ///
+    /// Unlike [`collect_array_checked`](CollectArray::collect_array_checked), which doesn't
+    /// consume the iterator and silently ignores a trailing remainder, this calls
+    /// [`next`](Iterator::next) once more after filling the array and errors with
+    /// [`too_many`](CollectorError::too_many) set if it yields [`Some`]. Useful when parsing
+    /// fixed-shape input, e.g. splitting a line that must have exactly `N` fields.
+    ///
+    /// ```
+    /// use collar::*;
+    /// let array: Result<[u8; 3], _> = [1, 2, 3].into_iter().collect_array_exact();
+    /// assert_eq!(array, Ok([1, 2, 3]));
+    ///
+    /// let err = [1, 2, 3, 4].into_iter().collect_array_exact::<3>().unwrap_err();
+    /// assert!(err.too_many);
+    ///
+    /// let err = [1, 2].into_iter().collect_array_exact::<3>().unwrap_err();
+    /// assert_eq!(err.at, 2);
+    /// assert!(!err.too_many);
+    /// ```
+    fn collect_array_exact<const N: usize>(&mut self) -> Result<[Self::Item; N], Error<N, ()>> {
+        let out = self.collect_array_checked().map_err(|at| Error {
+            error: None,
+            at,
+            too_many: false,
+        })?;
+        if self.next().is_some() {
+            return Err(Error {
+                error: None,
+                at: N,
+                too_many: true,
+            });
+        }
+        Ok(out)
+    }
+
     /// This function fills an array with this iterators elements.
     /// It will always return (unless the iterator panics).
     /// ```
@@ -105,9 +148,139 @@ pub trait CollectArray: Iterator + Sized {
     fn items<const N: usize>(&mut self) -> [Option<Self::Item>; N] {
         from_fn(|_| self.next())
     }
+
+    /// Fills an array with this iterator's elements, padding any leftover slots with `fill`.
+    /// Unlike [`items`](CollectArray::items), which wraps every element in [`Option`], this
+    /// returns the bare element type, so it's a drop-in for fixed-width buffers and SIMD-lane
+    /// packing where a padded tail is the desired behavior.
+    /// ```
+    /// use collar::*;
+    /// assert_eq!((0..3).collect_array_with::<5>(|i| i * 10), [0, 1, 2, 30, 40]);
+    /// ```
+    fn collect_array_with<const N: usize>(
+        &mut self,
+        mut fill: impl FnMut(usize) -> Self::Item,
+    ) -> [Self::Item; N] {
+        from_fn(|i| self.next().unwrap_or_else(|| fill(i)))
+    }
+
+    /// Fills an array with this iterator's elements, padding any leftover slots with
+    /// [`Default::default`]. See [`collect_array_with`](CollectArray::collect_array_with) to
+    /// supply the padding yourself.
+    /// ```
+    /// use collar::*;
+    /// assert_eq!((0..3).collect_array_or_default::<5>(), [0, 1, 2, 0, 0]);
+    /// ```
+    fn collect_array_or_default<const N: usize>(&mut self) -> [Self::Item; N]
+    where
+        Self::Item: Default,
+    {
+        self.collect_array_with(|_| Self::Item::default())
+    }
 }
 impl<I: Iterator> CollectArray for I {}
 
+/// Fallible analogue of [`FromIterator`], so collection targets beyond arrays can opt into
+/// the same "might run out of items" story as [`CollectArray`].
+pub trait TryFromIterator<A>: Sized {
+    /// Why the collection failed.
+    type Error;
+    /// Builds `Self` from `iter`, or fails partway through with [`Error`](TryFromIterator::Error).
+    fn try_from_iter<I: IntoIterator<Item = A>>(iter: I) -> Result<Self, Self::Error>;
+}
+
+impl<T, const N: usize> TryFromIterator<T> for [T; N] {
+    type Error = usize;
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        iter.into_iter().collect_array_checked()
+    }
+}
+
+/// Flattens `Result` element errors into the array collection itself, same as
+/// [`try_collect_array`](CollectArray::try_collect_array).
+impl<T, E, const N: usize> TryFromIterator<Result<T, E>> for [T; N] {
+    type Error = Error<N, E>;
+    fn try_from_iter<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, Self::Error> {
+        iter.into_iter().try_collect_array()
+    }
+}
+
+/// Flattens `Option` elements into the array collection itself, same as
+/// [`try_collect_array`](CollectArray::try_collect_array).
+impl<T, const N: usize> TryFromIterator<Option<T>> for [T; N] {
+    type Error = Error<N, ()>;
+    fn try_from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Result<Self, Self::Error> {
+        iter.into_iter().try_collect_array()
+    }
+}
+
+/// Fallible collection, the [`TryFromIterator`] counterpart to [`Iterator::collect`].
+pub trait IteratorExt: Iterator + Sized {
+    /// Fallibly collects this iterator into `B`, picking the implementation via inference,
+    /// e.g. `iter.try_collect::<[T; 8]>()`.
+    /// ```
+    /// use collar::*;
+    /// let array: Result<[u8; 5], _> = (0..).map(|x| x.try_into()).try_collect();
+    /// assert_eq!(array, Ok([0, 1, 2, 3, 4]));
+    ///
+    /// let array: Result<[u8; 4], usize> = [1, 2].into_iter().try_collect();
+    /// assert_eq!(array, Err(2));
+    /// ```
+    fn try_collect<B: TryFromIterator<Self::Item>>(self) -> Result<B, B::Error> {
+        B::try_from_iter(self)
+    }
+}
+impl<I: Iterator> IteratorExt for I {}
+
+/// Extensions for fixed-size arrays.
+pub trait ArrayExt<T, const N: usize> {
+    /// [`[T; N]::try_map`](array) on stable.
+    ///
+    /// Consumes the array by value, applying `f` to each element in order. Short-circuits on
+    /// the first [`None`]/[`Err`], dropping both the elements already mapped and the
+    /// not-yet-visited tail of the input array.
+    ///
+    /// The return type of this function depends on the return type of `f`: `Result<T, E>`
+    /// gives `Result<[T; N], E>`, `Option<T>` gives `Result<[T; N], ()>` (unlike
+    /// [`try_from_fn`], which gives `Option<[T; N]>` for `Option<T>`).
+    ///
+    /// ```
+    /// use collar::*;
+    /// let array: Result<[u32; 4], _> = [1u32, 2, 3, 4].try_map(|x| x.checked_mul(2).ok_or(x));
+    /// assert_eq!(array, Ok([2, 4, 6, 8]));
+    ///
+    /// let array: Result<[u8; 4], _> = [1, 2, 3, 4].try_map(|x: u32| x.try_into());
+    /// assert!(array.is_ok());
+    /// ```
+    fn try_map<R: Maybe, F: FnMut(T) -> R>(self, f: F) -> Result<[R::Unwrap; N], R::Or>;
+}
+
+impl<T, const N: usize> ArrayExt<T, N> for [T; N] {
+    fn try_map<R: Maybe, F: FnMut(T) -> R>(self, mut f: F) -> Result<[R::Unwrap; N], R::Or> {
+        let mut input = MD::new(self);
+        let mut out = [const { MU::uninit() }; N];
+        for elem in 0..N {
+            // SAFETY: `elem` has not been read out of `input` before.
+            let item = unsafe { (&raw const input[elem]).read() };
+            let guard = OnDrop::guard(|| unsafe {
+                // drop the already-mapped output prefix...
+                let out = &raw mut out[..elem] as *mut [R::Unwrap];
+                let guard = OnDrop::guard(|| drop_in_place(out));
+                drop_in_place(out);
+                // ...and the not-yet-visited input tail. `elem` itself was already read above.
+                drop_in_place(&raw mut input[elem + 1..]);
+                forget(guard);
+            });
+            let v = f(item).asr()?;
+            // dont drop!
+            forget(guard);
+            out[elem] = MU::new(v);
+        }
+        // SAFETY: each element has been initialized
+        Ok(unsafe { transmute_unchecked(out) })
+    }
+}
+
 struct OnDrop<F: FnOnce()> {
     f: MD<F>,
 }
@@ -140,9 +313,12 @@ const unsafe fn transmute_unchecked<T, U>(value: T) -> U {
 /// Unlike [`from_fn`], where the element creation can't fail, this version will return an error
 /// if any element creation was unsuccessful.
 ///
-/// The return type of this function depends on the return type of the closure.
+/// Generic over [`Branch`], so `cb` may return anything that short-circuits: [`Option`],
+/// [`Result`], or [`ControlFlow`]. The return type of this function depends on the return type
+/// of the closure.
 /// If you return `Result<T, E>` from the closure, you'll get a `Result<[T; N], E>`.
-/// If you return `Option<T>` from the closure, you'll get an `Result<[T; N], ()>`.
+/// If you return `Option<T>` from the closure, you'll get an `Option<[T; N]>`.
+/// If you return `ControlFlow<B, T>` from the closure, you'll get a `ControlFlow<B, [T; N]>`.
 ///
 /// # Arguments
 ///
@@ -157,32 +333,44 @@ const unsafe fn transmute_unchecked<T, U>(value: T) -> U {
 /// let array: Result<[i8; 200], _> = collar::try_from_fn(|i| i.try_into());
 /// assert!(array.is_err());
 ///
-/// let array: Option<[_; 4]> = collar::try_from_fn(|i| i.checked_add(100)).ok();
+/// let array: Option<[_; 4]> = collar::try_from_fn(|i| i.checked_add(100));
 /// assert_eq!(array, Some([100, 101, 102, 103]));
 ///
-/// let array: Option<[_; 4]> = collar::try_from_fn(|i| i.checked_sub(100)).ok();
+/// let array: Option<[_; 4]> = collar::try_from_fn(|i| i.checked_sub(100));
 /// assert_eq!(array, None);
+///
+/// use core::ops::ControlFlow;
+/// let array: ControlFlow<&str, [u8; 4]> =
+///     collar::try_from_fn(|i| if i == 3 { ControlFlow::Break("too far") } else { ControlFlow::Continue(i as u8) });
+/// assert_eq!(array, ControlFlow::Break("too far"));
 /// ```
-pub fn try_from_fn<R: Maybe, const N: usize>(
-    mut x: impl FnMut(usize) -> R,
-) -> Result<[R::Unwrap; N], R::Or> {
+pub fn try_from_fn<T: Branch, const N: usize>(
+    mut x: impl FnMut(usize) -> T,
+) -> <T::Residual as Residual<[T::Output; N]>>::TryType
+where
+    T::Residual: Residual<[T::Output; N]>,
+{
     let mut out = [const { MU::uninit() }; N];
     // initialize each element of `out`
     for elem in 0..N {
         let guard = OnDrop::guard(|| unsafe {
-            let p = &raw mut out[..elem] as *mut [R::Unwrap];
+            let p = &raw mut out[..elem] as *mut [T::Output];
             let guard = OnDrop::guard(|| drop_in_place(p));
             drop_in_place(p);
             // dont drop! (again)
             forget(guard);
         });
-        let e = x(elem).asr()?;
-        // dont drop!
-        forget(guard);
-        out[elem] = MU::new(e);
+        match x(elem).branch() {
+            ControlFlow::Continue(e) => {
+                // dont drop!
+                forget(guard);
+                out[elem] = MU::new(e);
+            }
+            ControlFlow::Break(r) => return FromResidual::from_residual(r),
+        }
     }
     // SAFETY: each element has been initialized
-    Ok(unsafe { transmute_unchecked(out) })
+    Branch::from_output(unsafe { transmute_unchecked(out) })
 }
 
 #[doc(no_inline)]